@@ -9,6 +9,10 @@
 //! ```
 
 use cfg_if::cfg_if;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 cfg_if! {
     if #[cfg(target_os = "windows")] {
@@ -39,43 +43,221 @@ pub enum Error {
     Unsupported(String),
 }
 
-/// Options for Linux (ignored on Windows/macOS).
+/// Which OS sleep/idle behaviors a [`ScreenWakeLock`] should inhibit.
+///
+/// The default, `DisplayAndSystem`, matches the crate's historical behavior
+/// of keeping both the display and the system awake. `SystemOnly` is useful
+/// for background work (e.g. a long download) that should survive the
+/// machine going idle without also forcing the monitor to stay lit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WakeLockKind {
+    /// Prevent both the display from blanking and the system from idling/suspending.
+    #[default]
+    DisplayAndSystem,
+    /// Prevent the system from idling/suspending; the display may still turn off.
+    SystemOnly,
+    /// Prevent the display from blanking; the system may still idle/suspend.
+    DisplayOnly,
+}
+
+/// Options specific to the Linux backend (ignored on Windows/macOS).
 #[derive(Clone, Debug)]
 pub struct LinuxOptions {
     /// D-Bus "application name" / app_id (often reverse-DNS). If None, a default is used.
     pub application_id: Option<String>,
-    /// Human readable reason. If None, the `reason` passed to `acquire*` is used.
-    pub reason: Option<String>,
+    /// Whether to fall back to periodically resetting the screensaver
+    /// (`ScreenSaverReset`) when no D-Bus inhibitor is available. Defaults
+    /// to `true`; set to `false` to instead return [`Error::Unsupported`].
+    pub allow_screensaver_fallback: bool,
 }
 
 impl Default for LinuxOptions {
     fn default() -> Self {
         Self {
             application_id: None,
-            reason: None,
+            allow_screensaver_fallback: true,
+        }
+    }
+}
+
+/// A periodic check that the wake lock is still held, re-acquiring it if not.
+///
+/// Some D-Bus inhibitor services silently drop their cookie across a service
+/// restart, a suspend/resume cycle, or a session-bus reconnect; on Windows
+/// and macOS the underlying handle can similarly go stale. A `Heartbeat`
+/// catches that by unconditionally refreshing the lock every `interval`,
+/// calling `on_failure` if re-acquisition itself fails.
+#[derive(Clone)]
+pub struct Heartbeat {
+    /// How often to verify/refresh the lock. Defaults to 60 seconds.
+    pub interval: Duration,
+    /// Called (off the caller's thread) if re-acquisition fails. The lock
+    /// keeps retrying on the next tick regardless.
+    pub on_failure: Option<Arc<dyn Fn(&Error) + Send + Sync>>,
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(60),
+            on_failure: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for Heartbeat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Heartbeat")
+            .field("interval", &self.interval)
+            .field("on_failure", &self.on_failure.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// Platform-neutral configuration for acquiring a [`ScreenWakeLock`].
+///
+/// Build one with [`ScreenWakeLock::builder`], or pass a reason string
+/// directly to [`ScreenWakeLock::acquire`] for the common case.
+#[derive(Clone, Debug)]
+pub struct AcquireOptions {
+    /// Human readable reason shown by the OS, if it surfaces one.
+    pub reason: String,
+    /// Which sleep behaviors to inhibit. Defaults to [`WakeLockKind::DisplayAndSystem`].
+    pub kind: WakeLockKind,
+    /// Optional watchdog that periodically re-asserts the lock.
+    pub heartbeat: Option<Heartbeat>,
+    /// Linux-specific knobs (ignored on Windows/macOS).
+    pub linux: LinuxOptions,
+}
+
+impl Default for AcquireOptions {
+    fn default() -> Self {
+        Self {
+            reason: String::new(),
+            kind: WakeLockKind::default(),
+            heartbeat: None,
+            linux: LinuxOptions::default(),
+        }
+    }
+}
+
+impl From<&str> for AcquireOptions {
+    fn from(reason: &str) -> Self {
+        Self {
+            reason: reason.to_string(),
+            ..Self::default()
         }
     }
 }
 
-/// Guard that keeps the **display** from idling/sleeping while alive.
+impl From<String> for AcquireOptions {
+    fn from(reason: String) -> Self {
+        Self {
+            reason,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<&String> for AcquireOptions {
+    fn from(reason: &String) -> Self {
+        reason.as_str().into()
+    }
+}
+
+/// Fluent builder for [`AcquireOptions`]; start with [`ScreenWakeLock::builder`].
+#[derive(Default)]
+pub struct AcquireOptionsBuilder {
+    options: AcquireOptions,
+}
+
+impl AcquireOptionsBuilder {
+    /// Human readable reason shown by the OS, if it surfaces one.
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.options.reason = reason.into();
+        self
+    }
+
+    /// D-Bus "application name" / app_id (Linux only; ignored elsewhere).
+    pub fn application_id(mut self, application_id: impl Into<String>) -> Self {
+        self.options.linux.application_id = Some(application_id.into());
+        self
+    }
+
+    /// Which sleep behaviors to inhibit. Defaults to [`WakeLockKind::DisplayAndSystem`].
+    pub fn kind(mut self, kind: WakeLockKind) -> Self {
+        self.options.kind = kind;
+        self
+    }
+
+    /// Whether Linux may fall back to periodically resetting the
+    /// screensaver when no D-Bus inhibitor is available. Defaults to `true`.
+    pub fn allow_screensaver_fallback(mut self, allow: bool) -> Self {
+        self.options.linux.allow_screensaver_fallback = allow;
+        self
+    }
+
+    /// Enable a [`Heartbeat`] with the given interval.
+    pub fn heartbeat(mut self, interval: Duration) -> Self {
+        self.options.heartbeat.get_or_insert_with(Heartbeat::default).interval = interval;
+        self
+    }
+
+    /// Set the [`Heartbeat`] failure callback, enabling the heartbeat with
+    /// its default interval first if [`Self::heartbeat`] hasn't been called yet.
+    pub fn on_heartbeat_failure(
+        mut self,
+        on_failure: impl Fn(&Error) + Send + Sync + 'static,
+    ) -> Self {
+        self.options.heartbeat.get_or_insert_with(Heartbeat::default).on_failure =
+            Some(Arc::new(on_failure));
+        self
+    }
+
+    /// Finish building without acquiring.
+    pub fn build(self) -> AcquireOptions {
+        self.options
+    }
+
+    /// Build and immediately acquire the lock.
+    pub fn acquire(self) -> Result<ScreenWakeLock, Error> {
+        ScreenWakeLock::acquire(self.options)
+    }
+
+    /// Build and immediately acquire an [`AsyncScreenWakeLock`].
+    pub async fn acquire_async(self) -> Result<AsyncScreenWakeLock, Error> {
+        AsyncScreenWakeLock::acquire(self.options).await
+    }
+}
+
+/// Guard that inhibits sleep/idle behavior while alive. Exactly which
+/// behaviors are inhibited depends on `options.kind` it was acquired with
+/// (see [`WakeLockKind`]); the display may still blank for a
+/// [`WakeLockKind::SystemOnly`] lock.
 pub struct ScreenWakeLock {
-    inner: sys::Inner,
+    inner: Arc<Mutex<sys::Inner>>,
+    heartbeat: Option<HeartbeatHandle>,
 }
 
 impl ScreenWakeLock {
-    /// Acquire a screen wake lock with a reason string.
-    pub fn acquire(reason: impl Into<String>) -> Result<Self, Error> {
-        Self::acquire_with_linux_options(reason, LinuxOptions::default())
-    }
-
-    /// Acquire, with extra Linux-specific options (safe to call on all platforms).
-    pub fn acquire_with_linux_options(
-        reason: impl Into<String>,
-        linux: LinuxOptions,
-    ) -> Result<Self, Error> {
-        let reason = reason.into();
-        let inner = sys::acquire(&reason, linux)?;
-        Ok(Self { inner })
+    /// Acquire a screen wake lock.
+    ///
+    /// Accepts either a plain reason string (equivalent to
+    /// [`WakeLockKind::DisplayAndSystem`] with no heartbeat) or a fully
+    /// configured [`AcquireOptions`], e.g. from [`ScreenWakeLock::builder`].
+    pub fn acquire(options: impl Into<AcquireOptions>) -> Result<Self, Error> {
+        let options = options.into();
+        let heartbeat_config = options.heartbeat;
+        let inner = sys::acquire(&options.reason, options.kind, options.linux)?;
+        let inner = Arc::new(Mutex::new(inner));
+        let heartbeat =
+            heartbeat_config.map(|config| HeartbeatHandle::spawn(Arc::clone(&inner), config));
+        Ok(Self { inner, heartbeat })
+    }
+
+    /// Start building an [`AcquireOptions`] with a fluent, platform-neutral API.
+    pub fn builder() -> AcquireOptionsBuilder {
+        AcquireOptionsBuilder::default()
     }
 
     /// Best-effort check (Linux: checks for a usable inhibitor service).
@@ -91,6 +273,190 @@ impl ScreenWakeLock {
 
 impl Drop for ScreenWakeLock {
     fn drop(&mut self) {
-        sys::release(&mut self.inner);
+        if let Some(heartbeat) = self.heartbeat.take() {
+            heartbeat.stop();
+        }
+        if let Ok(mut inner) = self.inner.lock() {
+            sys::release(&mut inner);
+        }
+    }
+}
+
+/// Background thread driving a [`Heartbeat`] for a single [`ScreenWakeLock`].
+struct HeartbeatHandle {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl HeartbeatHandle {
+    fn spawn(inner: Arc<Mutex<sys::Inner>>, heartbeat: Heartbeat) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_thread = Arc::clone(&stop);
+        let thread = thread::spawn(move || {
+            while !stop_thread.load(Ordering::Relaxed) {
+                sleep_unless_stopped(heartbeat.interval, &stop_thread);
+                if stop_thread.load(Ordering::Relaxed) {
+                    break;
+                }
+                let Ok(mut guard) = inner.lock() else {
+                    break;
+                };
+                let result = sys::heartbeat(&mut guard);
+                drop(guard);
+                if let Err(err) = result {
+                    if let Some(on_failure) = &heartbeat.on_failure {
+                        on_failure(&err);
+                    }
+                }
+            }
+        });
+        Self {
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    fn stop(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Sleeps for `duration`, waking up early (in small increments) as soon as
+/// `stop` is set, so stopping a heartbeat doesn't block for a full interval.
+fn sleep_unless_stopped(duration: Duration, stop: &AtomicBool) {
+    const POLL: Duration = Duration::from_millis(200);
+    let mut remaining = duration;
+    while remaining > Duration::ZERO && !stop.load(Ordering::Relaxed) {
+        let step = POLL.min(remaining);
+        thread::sleep(step);
+        remaining -= step;
+    }
+}
+
+/// Async counterpart to [`ScreenWakeLock`], built on non-blocking D-Bus calls
+/// on Linux (Windows/macOS just wrap their already-cheap synchronous calls).
+///
+/// Unlike `ScreenWakeLock`, this has no `Drop` impl: releasing some Linux
+/// backends requires an async D-Bus round trip, which `Drop::drop` cannot
+/// perform. Call [`AsyncScreenWakeLock::release`] explicitly before the guard
+/// goes out of scope.
+pub struct AsyncScreenWakeLock {
+    inner: sys::nonblocking::AsyncInner,
+}
+
+impl AsyncScreenWakeLock {
+    /// Acquire an async screen wake lock.
+    ///
+    /// Accepts either a plain reason string (equivalent to
+    /// [`WakeLockKind::DisplayAndSystem`] with no heartbeat) or a fully
+    /// configured [`AcquireOptions`], e.g. from [`ScreenWakeLock::builder`].
+    ///
+    /// `options.heartbeat` is rejected with [`Error::Unsupported`]: healing a
+    /// dropped lock needs something to drive the re-acquire `Future` on a
+    /// schedule, and this crate doesn't assume an async executor. Use
+    /// [`ScreenWakeLock`] (backed by a plain `std::thread`) if you need the
+    /// heartbeat's self-healing.
+    pub async fn acquire(options: impl Into<AcquireOptions>) -> Result<Self, Error> {
+        let options = options.into();
+        if options.heartbeat.is_some() {
+            return Err(Error::Unsupported(
+                "AsyncScreenWakeLock does not support a heartbeat; use ScreenWakeLock instead"
+                    .to_string(),
+            ));
+        }
+        let inner = sys::nonblocking::acquire(&options.reason, options.kind, options.linux).await?;
+        Ok(Self { inner })
+    }
+
+    /// Start building an [`AcquireOptions`] with a fluent, platform-neutral API.
+    pub fn builder() -> AcquireOptionsBuilder {
+        AcquireOptionsBuilder::default()
+    }
+
+    /// Best-effort check (Linux: checks for a session/system D-Bus connection,
+    /// or for what the screensaver-reset fallback needs to run).
+    pub fn is_supported() -> bool {
+        sys::nonblocking::is_supported()
+    }
+
+    /// Release the lock, awaiting any D-Bus calls needed to clear it.
+    pub async fn release(mut self) {
+        sys::nonblocking::release(&mut self.inner).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_and_string_into_acquire_options() {
+        let from_str: AcquireOptions = "Playing video".into();
+        assert_eq!(from_str.reason, "Playing video");
+        assert_eq!(from_str.kind, WakeLockKind::DisplayAndSystem);
+        assert!(from_str.heartbeat.is_none());
+
+        let from_string: AcquireOptions = "Playing video".to_string().into();
+        assert_eq!(from_string.reason, "Playing video");
+
+        let owned = "Playing video".to_string();
+        let from_ref_string: AcquireOptions = (&owned).into();
+        assert_eq!(from_ref_string.reason, "Playing video");
+    }
+
+    #[test]
+    fn builder_sets_reason_kind_and_linux_options() {
+        let options = AcquireOptionsBuilder::default()
+            .reason("Downloading update")
+            .kind(WakeLockKind::SystemOnly)
+            .application_id("com.example.app")
+            .allow_screensaver_fallback(false)
+            .build();
+
+        assert_eq!(options.reason, "Downloading update");
+        assert_eq!(options.kind, WakeLockKind::SystemOnly);
+        assert_eq!(
+            options.linux.application_id.as_deref(),
+            Some("com.example.app")
+        );
+        assert!(!options.linux.allow_screensaver_fallback);
+        assert!(options.heartbeat.is_none());
+    }
+
+    #[test]
+    fn builder_heartbeat_sets_interval_without_callback() {
+        let options = AcquireOptionsBuilder::default()
+            .heartbeat(Duration::from_secs(30))
+            .build();
+
+        let heartbeat = options.heartbeat.expect("heartbeat should be enabled");
+        assert_eq!(heartbeat.interval, Duration::from_secs(30));
+        assert!(heartbeat.on_failure.is_none());
+    }
+
+    #[test]
+    fn builder_on_heartbeat_failure_enables_heartbeat_with_default_interval() {
+        let options = AcquireOptionsBuilder::default()
+            .on_heartbeat_failure(|_err| {})
+            .build();
+
+        let heartbeat = options.heartbeat.expect("heartbeat should be enabled");
+        assert_eq!(heartbeat.interval, Duration::from_secs(60));
+        assert!(heartbeat.on_failure.is_some());
+    }
+
+    #[test]
+    fn builder_on_heartbeat_failure_preserves_explicit_interval() {
+        let options = AcquireOptionsBuilder::default()
+            .heartbeat(Duration::from_secs(5))
+            .on_heartbeat_failure(|_err| {})
+            .build();
+
+        let heartbeat = options.heartbeat.expect("heartbeat should be enabled");
+        assert_eq!(heartbeat.interval, Duration::from_secs(5));
+        assert!(heartbeat.on_failure.is_some());
     }
 }
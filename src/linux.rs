@@ -1,10 +1,84 @@
-use crate::{Error, LinuxOptions};
+use crate::{Error, LinuxOptions, WakeLockKind};
 use std::collections::BTreeMap;
+use std::process::Command;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 use zbus::blocking::{Connection, Proxy};
 use zbus::zvariant::{OwnedFd, OwnedObjectPath, OwnedValue, Str};
 
-const GNOME_INHIBIT_IDLE: u32 = 8;
-const PORTAL_INHIBIT_IDLE: u32 = 8;
+/// How often the no-D-Bus fallback resets the idle timer.
+const SCREENSAVER_RESET_INTERVAL: Duration = Duration::from_secs(30);
+/// Granularity at which the fallback thread checks for a stop request.
+const SCREENSAVER_RESET_POLL: Duration = Duration::from_millis(200);
+
+// GNOME session / xdg-desktop-portal inhibit flags (bitmask).
+const INHIBIT_FLAG_SUSPEND: u32 = 4;
+const INHIBIT_FLAG_IDLE: u32 = 8;
+
+/// Inhibit flags to request for a given [`WakeLockKind`].
+fn inhibit_flags(kind: WakeLockKind) -> u32 {
+    match kind {
+        WakeLockKind::DisplayAndSystem => INHIBIT_FLAG_IDLE | INHIBIT_FLAG_SUSPEND,
+        WakeLockKind::SystemOnly => INHIBIT_FLAG_SUSPEND,
+        WakeLockKind::DisplayOnly => INHIBIT_FLAG_IDLE,
+    }
+}
+
+/// `systemd-logind` `Inhibit` "what" argument for a given [`WakeLockKind`].
+fn logind_what(kind: WakeLockKind) -> &'static str {
+    match kind {
+        WakeLockKind::DisplayAndSystem => "idle:sleep",
+        WakeLockKind::SystemOnly => "sleep",
+        WakeLockKind::DisplayOnly => "idle",
+    }
+}
+
+/// A backend the sync and async `acquire` share the same attempt order for.
+///
+/// The actual D-Bus calls differ between `zbus::blocking` and `zbus` (and so
+/// stay as two small sets of `try_*` functions below/in [`nonblocking`]), but
+/// *which backend to try, in what order, against which connection* is pure
+/// decision logic — `PROBE_ORDER` is the one place that changes if a step is
+/// added, removed, or reordered, so the sync and async paths can't drift.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ProbeStep {
+    GnomeSession,
+    FdoScreenSaver,
+    FdoPowerManagement,
+    XdgPortal,
+    Logind,
+    ScreenSaverReset,
+}
+
+/// Prefer session-bus mechanisms (`GnomeSession`, then the two narrower
+/// `org.freedesktop` inhibitors, then the xdg-desktop-portal), then the
+/// system-bus `Logind` inhibitor, then the no-D-Bus screensaver-reset
+/// fallback as a last resort.
+const PROBE_ORDER: &[ProbeStep] = &[
+    ProbeStep::GnomeSession,
+    ProbeStep::FdoScreenSaver,
+    ProbeStep::FdoPowerManagement,
+    ProbeStep::XdgPortal,
+    ProbeStep::Logind,
+    ProbeStep::ScreenSaverReset,
+];
+
+impl ProbeStep {
+    /// Whether this step can even express `kind`'s request. `FdoScreenSaver`
+    /// and `FdoPowerManagement` only ever inhibit idling, and `ScreenSaverReset`
+    /// can only keep the display from blanking, so none of them can stand in
+    /// for a system-only request.
+    fn supports(self, kind: WakeLockKind) -> bool {
+        match self {
+            ProbeStep::FdoScreenSaver
+            | ProbeStep::FdoPowerManagement
+            | ProbeStep::ScreenSaverReset => kind != WakeLockKind::SystemOnly,
+            ProbeStep::GnomeSession | ProbeStep::XdgPortal | ProbeStep::Logind => true,
+        }
+    }
+}
 
 enum Backend {
     // Session bus APIs (cookie + same connection must remain alive)
@@ -30,6 +104,128 @@ enum Backend {
         _conn: Connection,
         _fd: OwnedFd,
     },
+
+    // No D-Bus inhibitor available: periodically reset the idle timer instead.
+    ScreenSaverReset {
+        handle: ScreenSaverResetHandle,
+    },
+}
+
+/// Background thread that keeps resetting the idle timer, for environments
+/// with no session/system bus inhibitor (e.g. minimal X11 setups).
+pub struct ScreenSaverResetHandle {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ScreenSaverResetHandle {
+    fn spawn() -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_thread = Arc::clone(&stop);
+        let thread = thread::spawn(move || {
+            while !stop_thread.load(Ordering::Relaxed) {
+                reset_idle_timer();
+                sleep_unless_stopped(SCREENSAVER_RESET_INTERVAL, &stop_thread);
+            }
+        });
+        Self {
+            stop,
+            thread: Some(thread),
+        }
+    }
+
+    fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for ScreenSaverResetHandle {
+    /// Stops the background thread even if the holder never calls
+    /// [`ScreenSaverResetHandle::stop`] explicitly — e.g. an
+    /// `AsyncScreenWakeLock` dropped without `.release().await`, a future
+    /// cancelled mid-`select!`, or an unwinding panic. Joining here is safe:
+    /// this is a plain `std::thread` join, not an async await.
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Sleeps for `duration`, but wakes up early (in `SCREENSAVER_RESET_POLL`
+/// increments) as soon as `stop` is set, so `release`/`Drop` isn't held up
+/// for a full interval.
+fn sleep_unless_stopped(duration: Duration, stop: &AtomicBool) {
+    let mut remaining = duration;
+    while remaining > Duration::ZERO && !stop.load(Ordering::Relaxed) {
+        let step = SCREENSAVER_RESET_POLL.min(remaining);
+        thread::sleep(step);
+        remaining -= step;
+    }
+}
+
+/// Xlib is only thread-safe once `XInitThreads()` has run, and it must run
+/// before *any* other Xlib call in the process — including ones made by a
+/// host app's own GTK/Qt/winit/raw-X11 code on a different thread. This
+/// calls it exactly once, before this crate's first `XOpenDisplay`; it does
+/// not order that call ahead of Xlib calls the host app has already made.
+fn ensure_xlib_threads_initialized() {
+    static INIT: std::sync::Once = std::sync::Once::new();
+    INIT.call_once(|| unsafe {
+        x11::xlib::XInitThreads();
+    });
+}
+
+/// Resets the idle timer via direct X11 calls when a display is reachable,
+/// falling back to shelling out to `xdg-screensaver reset`.
+fn reset_idle_timer() {
+    if reset_idle_timer_x11().is_none() {
+        let _ = Command::new("xdg-screensaver").arg("reset").status();
+    }
+}
+
+fn reset_idle_timer_x11() -> Option<()> {
+    ensure_xlib_threads_initialized();
+    unsafe {
+        let display = x11::xlib::XOpenDisplay(std::ptr::null());
+        if display.is_null() {
+            return None;
+        }
+        x11::xlib::XResetScreenSaver(display);
+        x11::xlib::XForceScreenSaver(display, x11::xlib::ScreenSaverReset);
+        x11::xlib::XCloseDisplay(display);
+    }
+    Some(())
+}
+
+/// Checks that an X11 display is reachable, without resetting anything.
+/// Used only to answer "could the fallback do something", not to act.
+fn x11_display_reachable() -> bool {
+    ensure_xlib_threads_initialized();
+    unsafe {
+        let display = x11::xlib::XOpenDisplay(std::ptr::null());
+        if display.is_null() {
+            return false;
+        }
+        x11::xlib::XCloseDisplay(display);
+    }
+    true
+}
+
+/// Whether `name` resolves to an executable on `PATH`, without running it.
+fn command_exists(name: &str) -> bool {
+    let Some(path_var) = std::env::var_os("PATH") else {
+        return false;
+    };
+    std::env::split_paths(&path_var).any(|dir| dir.join(name).is_file())
+}
+
+/// Whether the no-D-Bus fallback could actually do anything: it needs either
+/// a reachable X11 display or the `xdg-screensaver` binary. Without either,
+/// its background thread would silently no-op forever.
+fn screensaver_reset_supported() -> bool {
+    x11_display_reachable() || command_exists("xdg-screensaver")
 }
 
 pub struct PlatformWakeLock {
@@ -37,41 +233,91 @@ pub struct PlatformWakeLock {
 }
 
 impl PlatformWakeLock {
-    pub fn acquire(application_id: &str, reason: &str) -> Result<Self, Error> {
-        // Prefer session-bus mechanisms when available.
-        if let Ok(conn) = Connection::session() {
-            if let Ok(cookie) = try_gnome_session(&conn, application_id, reason) {
-                return Ok(Self {
-                    backend: Backend::GnomeSession { conn, cookie },
-                });
-            }
-            if let Ok(cookie) = try_fdo_screensaver(&conn, application_id, reason) {
-                return Ok(Self {
-                    backend: Backend::FdoScreenSaver { conn, cookie },
-                });
-            }
-            if let Ok(cookie) = try_fdo_powermanagement(&conn, application_id, reason) {
-                return Ok(Self {
-                    backend: Backend::FdoPowerManagement { conn, cookie },
-                });
+    pub fn acquire(
+        application_id: &str,
+        reason: &str,
+        kind: WakeLockKind,
+        allow_screensaver_fallback: bool,
+    ) -> Result<Self, Error> {
+        let flags = inhibit_flags(kind);
+        let session_conn = Connection::session().ok();
+
+        for step in PROBE_ORDER {
+            if !step.supports(kind) {
+                continue;
             }
-            if let Ok(handle) = try_xdg_portal(&conn, reason) {
-                return Ok(Self {
-                    backend: Backend::XdgPortal { conn, handle },
-                });
+            match step {
+                ProbeStep::GnomeSession => {
+                    if let Some(conn) = &session_conn {
+                        if let Ok(cookie) = try_gnome_session(conn, application_id, reason, flags)
+                        {
+                            return Ok(Self {
+                                backend: Backend::GnomeSession {
+                                    conn: conn.clone(),
+                                    cookie,
+                                },
+                            });
+                        }
+                    }
+                }
+                ProbeStep::FdoScreenSaver => {
+                    if let Some(conn) = &session_conn {
+                        if let Ok(cookie) = try_fdo_screensaver(conn, application_id, reason) {
+                            return Ok(Self {
+                                backend: Backend::FdoScreenSaver {
+                                    conn: conn.clone(),
+                                    cookie,
+                                },
+                            });
+                        }
+                    }
+                }
+                ProbeStep::FdoPowerManagement => {
+                    if let Some(conn) = &session_conn {
+                        if let Ok(cookie) = try_fdo_powermanagement(conn, application_id, reason) {
+                            return Ok(Self {
+                                backend: Backend::FdoPowerManagement {
+                                    conn: conn.clone(),
+                                    cookie,
+                                },
+                            });
+                        }
+                    }
+                }
+                ProbeStep::XdgPortal => {
+                    if let Some(conn) = &session_conn {
+                        if let Ok(handle) = try_xdg_portal(conn, reason, flags) {
+                            return Ok(Self {
+                                backend: Backend::XdgPortal {
+                                    conn: conn.clone(),
+                                    handle,
+                                },
+                            });
+                        }
+                    }
+                }
+                ProbeStep::Logind => {
+                    if let Ok((conn, fd)) = try_logind(application_id, reason, kind) {
+                        return Ok(Self {
+                            backend: Backend::Logind {
+                                _conn: conn,
+                                _fd: fd,
+                            },
+                        });
+                    }
+                }
+                ProbeStep::ScreenSaverReset => {
+                    if allow_screensaver_fallback {
+                        return Ok(Self {
+                            backend: Backend::ScreenSaverReset {
+                                handle: ScreenSaverResetHandle::spawn(),
+                            },
+                        });
+                    }
+                }
             }
         }
 
-        // Fallback: systemd-logind idle inhibitor (system bus).
-        if let Ok((conn, fd)) = try_logind(application_id, reason) {
-            return Ok(Self {
-                backend: Backend::Logind {
-                    _conn: conn,
-                    _fd: fd,
-                },
-            });
-        }
-
         Err(Error::Unsupported(
             "no suitable Linux inhibition backend found".to_string(),
         ))
@@ -128,6 +374,10 @@ impl PlatformWakeLock {
                 // The inhibitor is released when the FD is closed (dropped).
                 Ok(())
             }
+            Backend::ScreenSaverReset { handle } => {
+                handle.stop();
+                Ok(())
+            }
         }
     }
 }
@@ -135,23 +385,38 @@ impl PlatformWakeLock {
 pub struct Inner {
     lock: Option<PlatformWakeLock>,
     active: bool,
+    application_id: String,
+    reason: String,
+    kind: WakeLockKind,
+    allow_screensaver_fallback: bool,
 }
 
 pub fn is_supported() -> bool {
-    Connection::session().is_ok() || Connection::system().is_ok()
+    // A live D-Bus connection doesn't guarantee a service answers on the
+    // other end, but it's the cheapest signal short of running the whole
+    // probe chain. Otherwise, fall back to the fallback's own capability
+    // check so this doesn't claim support a headless, X11-less,
+    // xdg-screensaver-less box can't actually deliver.
+    Connection::session().is_ok() || Connection::system().is_ok() || screensaver_reset_supported()
 }
 
-pub fn acquire(reason: &str, linux: LinuxOptions) -> Result<Inner, Error> {
+pub fn acquire(reason: &str, kind: WakeLockKind, linux: LinuxOptions) -> Result<Inner, Error> {
     let application_id = linux
         .application_id
         .as_deref()
-        .unwrap_or("screen_wake_lock");
-    let effective_reason = linux.reason.as_deref().unwrap_or(reason);
+        .unwrap_or("screen_wake_lock")
+        .to_string();
+    let allow_screensaver_fallback = linux.allow_screensaver_fallback;
 
-    let lock = PlatformWakeLock::acquire(application_id, effective_reason)?;
+    let lock =
+        PlatformWakeLock::acquire(&application_id, reason, kind, allow_screensaver_fallback)?;
     Ok(Inner {
         lock: Some(lock),
         active: true,
+        application_id,
+        reason: reason.to_string(),
+        kind,
+        allow_screensaver_fallback,
     })
 }
 
@@ -166,17 +431,43 @@ pub fn release(inner: &mut Inner) {
     inner.active = false;
 }
 
-fn try_gnome_session(conn: &Connection, application_id: &str, reason: &str) -> zbus::Result<u32> {
+/// Re-acquires the inhibitor from scratch, swapping in the fresh cookie/fd.
+/// Simplest and most robust way to heal a dropped cookie: rather than trying
+/// to verify the old one is still registered (no backend offers a direct
+/// "is this cookie still valid?" query), just refresh unconditionally.
+pub fn heartbeat(inner: &mut Inner) -> Result<(), Error> {
+    if !inner.active {
+        return Ok(());
+    }
+    match PlatformWakeLock::acquire(
+        &inner.application_id,
+        &inner.reason,
+        inner.kind,
+        inner.allow_screensaver_fallback,
+    ) {
+        Ok(new_lock) => {
+            if let Some(old) = inner.lock.replace(new_lock) {
+                let _ = old.release();
+            }
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn try_gnome_session(
+    conn: &Connection,
+    application_id: &str,
+    reason: &str,
+    flags: u32,
+) -> zbus::Result<u32> {
     let proxy = Proxy::new(
         conn,
         "org.gnome.SessionManager",
         "/org/gnome/SessionManager",
         "org.gnome.SessionManager",
     )?;
-    let cookie: u32 = proxy.call(
-        "Inhibit",
-        &(application_id, 0u32, reason, GNOME_INHIBIT_IDLE),
-    )?;
+    let cookie: u32 = proxy.call("Inhibit", &(application_id, 0u32, reason, flags))?;
     Ok(cookie)
 }
 
@@ -206,7 +497,11 @@ fn try_fdo_powermanagement(
     Ok(cookie)
 }
 
-fn try_xdg_portal(conn: &Connection, reason: &str) -> zbus::Result<OwnedObjectPath> {
+fn try_xdg_portal(
+    conn: &Connection,
+    reason: &str,
+    flags: u32,
+) -> zbus::Result<OwnedObjectPath> {
     let proxy = Proxy::new(
         conn,
         "org.freedesktop.portal.Desktop",
@@ -217,12 +512,15 @@ fn try_xdg_portal(conn: &Connection, reason: &str) -> zbus::Result<OwnedObjectPa
     let mut options: BTreeMap<String, OwnedValue> = BTreeMap::new();
     options.insert("reason".to_string(), OwnedValue::from(Str::from(reason)));
 
-    // flags: 8 = Idle
-    let handle: OwnedObjectPath = proxy.call("Inhibit", &("", PORTAL_INHIBIT_IDLE, options))?;
+    let handle: OwnedObjectPath = proxy.call("Inhibit", &("", flags, options))?;
     Ok(handle)
 }
 
-fn try_logind(application_id: &str, reason: &str) -> zbus::Result<(Connection, OwnedFd)> {
+fn try_logind(
+    application_id: &str,
+    reason: &str,
+    kind: WakeLockKind,
+) -> zbus::Result<(Connection, OwnedFd)> {
     let conn = Connection::system()?;
     let proxy = Proxy::new(
         &conn,
@@ -231,7 +529,339 @@ fn try_logind(application_id: &str, reason: &str) -> zbus::Result<(Connection, O
         "org.freedesktop.login1.Manager",
     )?;
 
-    // what: "idle" (inhibit idle actions), mode: "block".
-    let fd: OwnedFd = proxy.call("Inhibit", &("idle", application_id, reason, "block"))?;
+    let what = logind_what(kind);
+    let fd: OwnedFd = proxy.call("Inhibit", &(what, application_id, reason, "block"))?;
     Ok((conn, fd))
 }
+
+/// Async counterpart of the blocking backend above, built on `zbus::Connection`
+/// instead of `zbus::blocking::Connection`. Backend selection shares
+/// [`super::PROBE_ORDER`] with `PlatformWakeLock::acquire`, so the attempt
+/// order can't drift between the two; only the per-backend D-Bus calls
+/// (genuinely different types between `zbus::blocking` and `zbus`) are
+/// duplicated below.
+pub mod nonblocking {
+    use super::{
+        Error, LinuxOptions, OwnedFd, OwnedObjectPath, OwnedValue, PROBE_ORDER, ProbeStep,
+        ScreenSaverResetHandle, Str, WakeLockKind, inhibit_flags, logind_what,
+    };
+    use std::collections::BTreeMap;
+    use zbus::Connection;
+    use zbus::Proxy;
+
+    enum Backend {
+        GnomeSession {
+            conn: Connection,
+            cookie: u32,
+        },
+        FdoScreenSaver {
+            conn: Connection,
+            cookie: u32,
+        },
+        FdoPowerManagement {
+            conn: Connection,
+            cookie: u32,
+        },
+        XdgPortal {
+            conn: Connection,
+            handle: OwnedObjectPath,
+        },
+        Logind {
+            _conn: Connection,
+            _fd: OwnedFd,
+        },
+        ScreenSaverReset {
+            handle: ScreenSaverResetHandle,
+        },
+    }
+
+    pub struct AsyncInner {
+        backend: Option<Backend>,
+        active: bool,
+    }
+
+    pub fn is_supported() -> bool {
+        super::is_supported()
+    }
+
+    pub async fn acquire(
+        reason: &str,
+        kind: WakeLockKind,
+        linux: LinuxOptions,
+    ) -> Result<AsyncInner, Error> {
+        let application_id = linux
+            .application_id
+            .as_deref()
+            .unwrap_or("screen_wake_lock");
+        let allow_screensaver_fallback = linux.allow_screensaver_fallback;
+        let flags = inhibit_flags(kind);
+        let session_conn = Connection::session().await.ok();
+
+        for step in PROBE_ORDER {
+            if !step.supports(kind) {
+                continue;
+            }
+            match step {
+                ProbeStep::GnomeSession => {
+                    if let Some(conn) = &session_conn {
+                        if let Ok(cookie) =
+                            try_gnome_session(conn, application_id, reason, flags).await
+                        {
+                            return Ok(AsyncInner {
+                                backend: Some(Backend::GnomeSession {
+                                    conn: conn.clone(),
+                                    cookie,
+                                }),
+                                active: true,
+                            });
+                        }
+                    }
+                }
+                ProbeStep::FdoScreenSaver => {
+                    if let Some(conn) = &session_conn {
+                        if let Ok(cookie) =
+                            try_fdo_screensaver(conn, application_id, reason).await
+                        {
+                            return Ok(AsyncInner {
+                                backend: Some(Backend::FdoScreenSaver {
+                                    conn: conn.clone(),
+                                    cookie,
+                                }),
+                                active: true,
+                            });
+                        }
+                    }
+                }
+                ProbeStep::FdoPowerManagement => {
+                    if let Some(conn) = &session_conn {
+                        if let Ok(cookie) =
+                            try_fdo_powermanagement(conn, application_id, reason).await
+                        {
+                            return Ok(AsyncInner {
+                                backend: Some(Backend::FdoPowerManagement {
+                                    conn: conn.clone(),
+                                    cookie,
+                                }),
+                                active: true,
+                            });
+                        }
+                    }
+                }
+                ProbeStep::XdgPortal => {
+                    if let Some(conn) = &session_conn {
+                        if let Ok(handle) = try_xdg_portal(conn, reason, flags).await {
+                            return Ok(AsyncInner {
+                                backend: Some(Backend::XdgPortal {
+                                    conn: conn.clone(),
+                                    handle,
+                                }),
+                                active: true,
+                            });
+                        }
+                    }
+                }
+                ProbeStep::Logind => {
+                    if let Ok((conn, fd)) = try_logind(application_id, reason, kind).await {
+                        return Ok(AsyncInner {
+                            backend: Some(Backend::Logind {
+                                _conn: conn,
+                                _fd: fd,
+                            }),
+                            active: true,
+                        });
+                    }
+                }
+                ProbeStep::ScreenSaverReset => {
+                    if allow_screensaver_fallback {
+                        return Ok(AsyncInner {
+                            backend: Some(Backend::ScreenSaverReset {
+                                handle: ScreenSaverResetHandle::spawn(),
+                            }),
+                            active: true,
+                        });
+                    }
+                }
+            }
+        }
+
+        Err(Error::Unsupported(
+            "no suitable Linux inhibition backend found".to_string(),
+        ))
+    }
+
+    pub async fn release(inner: &mut AsyncInner) {
+        if !inner.active {
+            return;
+        }
+        if let Some(backend) = inner.backend.take() {
+            // Best-effort: ignore D-Bus errors during release.
+            match backend {
+                Backend::GnomeSession { conn, cookie } => {
+                    if let Ok(proxy) = Proxy::new(
+                        &conn,
+                        "org.gnome.SessionManager",
+                        "/org/gnome/SessionManager",
+                        "org.gnome.SessionManager",
+                    )
+                    .await
+                    {
+                        let _: zbus::Result<()> = proxy.call("Uninhibit", &(cookie)).await;
+                    }
+                }
+                Backend::FdoScreenSaver { conn, cookie } => {
+                    if let Ok(proxy) = Proxy::new(
+                        &conn,
+                        "org.freedesktop.ScreenSaver",
+                        "/org/freedesktop/ScreenSaver",
+                        "org.freedesktop.ScreenSaver",
+                    )
+                    .await
+                    {
+                        let _: zbus::Result<()> = proxy.call("UnInhibit", &(cookie)).await;
+                    }
+                }
+                Backend::FdoPowerManagement { conn, cookie } => {
+                    if let Ok(proxy) = Proxy::new(
+                        &conn,
+                        "org.freedesktop.PowerManagement",
+                        "/org/freedesktop/PowerManagement/Inhibit",
+                        "org.freedesktop.PowerManagement.Inhibit",
+                    )
+                    .await
+                    {
+                        let _: zbus::Result<()> = proxy.call("UnInhibit", &(cookie)).await;
+                    }
+                }
+                Backend::XdgPortal { conn, handle } => {
+                    if let Ok(proxy) = Proxy::new(
+                        &conn,
+                        "org.freedesktop.portal.Desktop",
+                        handle,
+                        "org.freedesktop.portal.Request",
+                    )
+                    .await
+                    {
+                        let _: zbus::Result<()> = proxy.call("Close", &()).await;
+                    }
+                }
+                Backend::Logind { .. } => {
+                    // The inhibitor is released when the FD is closed (dropped).
+                }
+                Backend::ScreenSaverReset { handle } => {
+                    handle.stop();
+                }
+            }
+        }
+        inner.active = false;
+    }
+
+    async fn try_gnome_session(
+        conn: &Connection,
+        application_id: &str,
+        reason: &str,
+        flags: u32,
+    ) -> zbus::Result<u32> {
+        let proxy = Proxy::new(
+            conn,
+            "org.gnome.SessionManager",
+            "/org/gnome/SessionManager",
+            "org.gnome.SessionManager",
+        )
+        .await?;
+        proxy
+            .call("Inhibit", &(application_id, 0u32, reason, flags))
+            .await
+    }
+
+    async fn try_fdo_screensaver(
+        conn: &Connection,
+        application_id: &str,
+        reason: &str,
+    ) -> zbus::Result<u32> {
+        let proxy = Proxy::new(
+            conn,
+            "org.freedesktop.ScreenSaver",
+            "/org/freedesktop/ScreenSaver",
+            "org.freedesktop.ScreenSaver",
+        )
+        .await?;
+        proxy.call("Inhibit", &(application_id, reason)).await
+    }
+
+    async fn try_fdo_powermanagement(
+        conn: &Connection,
+        application_id: &str,
+        reason: &str,
+    ) -> zbus::Result<u32> {
+        let proxy = Proxy::new(
+            conn,
+            "org.freedesktop.PowerManagement",
+            "/org/freedesktop/PowerManagement/Inhibit",
+            "org.freedesktop.PowerManagement.Inhibit",
+        )
+        .await?;
+        proxy.call("Inhibit", &(application_id, reason)).await
+    }
+
+    async fn try_xdg_portal(
+        conn: &Connection,
+        reason: &str,
+        flags: u32,
+    ) -> zbus::Result<OwnedObjectPath> {
+        let proxy = Proxy::new(
+            conn,
+            "org.freedesktop.portal.Desktop",
+            "/org/freedesktop/portal/desktop",
+            "org.freedesktop.portal.Inhibit",
+        )
+        .await?;
+
+        let mut options: BTreeMap<String, OwnedValue> = BTreeMap::new();
+        options.insert("reason".to_string(), OwnedValue::from(Str::from(reason)));
+
+        proxy.call("Inhibit", &("", flags, options)).await
+    }
+
+    async fn try_logind(
+        application_id: &str,
+        reason: &str,
+        kind: WakeLockKind,
+    ) -> zbus::Result<(Connection, OwnedFd)> {
+        let conn = Connection::system().await?;
+        let proxy = Proxy::new(
+            &conn,
+            "org.freedesktop.login1",
+            "/org/freedesktop/login1",
+            "org.freedesktop.login1.Manager",
+        )
+        .await?;
+
+        let what = logind_what(kind);
+        let fd: OwnedFd = proxy
+            .call("Inhibit", &(what, application_id, reason, "block"))
+            .await?;
+        Ok((conn, fd))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inhibit_flags_match_kind() {
+        assert_eq!(
+            inhibit_flags(WakeLockKind::DisplayAndSystem),
+            INHIBIT_FLAG_IDLE | INHIBIT_FLAG_SUSPEND
+        );
+        assert_eq!(inhibit_flags(WakeLockKind::SystemOnly), INHIBIT_FLAG_SUSPEND);
+        assert_eq!(inhibit_flags(WakeLockKind::DisplayOnly), INHIBIT_FLAG_IDLE);
+    }
+
+    #[test]
+    fn logind_what_matches_kind() {
+        assert_eq!(logind_what(WakeLockKind::DisplayAndSystem), "idle:sleep");
+        assert_eq!(logind_what(WakeLockKind::SystemOnly), "sleep");
+        assert_eq!(logind_what(WakeLockKind::DisplayOnly), "idle");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Error, LinuxOptions};
+use crate::{Error, LinuxOptions, WakeLockKind};
 use objc2_core_foundation::CFString;
 use objc2_io_kit::{
     IOPMAssertionCreateWithName, IOPMAssertionID, IOPMAssertionRelease, kIOPMAssertionLevelOn,
@@ -6,43 +6,146 @@ use objc2_io_kit::{
 };
 
 const ASSERTION_TYPE_NO_DISPLAY_SLEEP: &str = "NoDisplaySleepAssertion";
+const ASSERTION_TYPE_PREVENT_IDLE_SYSTEM_SLEEP: &str = "PreventUserIdleSystemSleep";
+
+/// Assertion type names to create for a given [`WakeLockKind`].
+///
+/// `DisplayAndSystem` creates both assertions explicitly: on modern macOS,
+/// `NoDisplaySleepAssertion` alone does not reliably prevent idle system sleep.
+fn assertion_types(kind: WakeLockKind) -> &'static [&'static str] {
+    match kind {
+        WakeLockKind::DisplayAndSystem => &[
+            ASSERTION_TYPE_NO_DISPLAY_SLEEP,
+            ASSERTION_TYPE_PREVENT_IDLE_SYSTEM_SLEEP,
+        ],
+        WakeLockKind::SystemOnly => &[ASSERTION_TYPE_PREVENT_IDLE_SYSTEM_SLEEP],
+        WakeLockKind::DisplayOnly => &[ASSERTION_TYPE_NO_DISPLAY_SLEEP],
+    }
+}
 
 pub struct Inner {
-    id: IOPMAssertionID,
+    ids: Vec<IOPMAssertionID>,
     active: bool,
+    reason: String,
+    kind: WakeLockKind,
 }
 
 pub fn is_supported() -> bool {
     true
 }
 
-pub fn acquire(reason: &str, _linux: LinuxOptions) -> Result<Inner, Error> {
-    let assertion_type = CFString::from_static_str(ASSERTION_TYPE_NO_DISPLAY_SLEEP);
+/// Creates one IOPM assertion per type required by `kind`, rolling back
+/// whatever was already created if a later one fails.
+fn create_assertions(reason: &str, kind: WakeLockKind) -> Result<Vec<IOPMAssertionID>, Error> {
     let assertion_name = CFString::from_str(reason);
 
-    let mut id: IOPMAssertionID = 0;
-    let rc = unsafe {
-        IOPMAssertionCreateWithName(
-            Some(&assertion_type),
-            kIOPMAssertionLevelOn,
-            Some(&assertion_name),
-            &mut id as *mut IOPMAssertionID,
-        )
-    };
+    let mut ids = Vec::new();
+    for assertion_type in assertion_types(kind) {
+        let assertion_type = CFString::from_static_str(assertion_type);
+
+        let mut id: IOPMAssertionID = 0;
+        let rc = unsafe {
+            IOPMAssertionCreateWithName(
+                Some(&assertion_type),
+                kIOPMAssertionLevelOn,
+                Some(&assertion_name),
+                &mut id as *mut IOPMAssertionID,
+            )
+        };
 
-    if rc != kIOReturnSuccess {
-        return Err(Error::Os(format!(
-            "IOPMAssertionCreateWithName failed (IOReturn={rc})"
-        )));
+        if rc != kIOReturnSuccess {
+            for id in &ids {
+                let _ = IOPMAssertionRelease(*id);
+            }
+            return Err(Error::Os(format!(
+                "IOPMAssertionCreateWithName failed (IOReturn={rc})"
+            )));
+        }
+        ids.push(id);
     }
 
-    Ok(Inner { id, active: true })
+    Ok(ids)
+}
+
+pub fn acquire(reason: &str, kind: WakeLockKind, _linux: LinuxOptions) -> Result<Inner, Error> {
+    let ids = create_assertions(reason, kind)?;
+    Ok(Inner {
+        ids,
+        active: true,
+        reason: reason.to_string(),
+        kind,
+    })
 }
 
 pub fn release(inner: &mut Inner) {
     if !inner.active {
         return;
     }
-    let _ = IOPMAssertionRelease(inner.id);
+    for id in inner.ids.drain(..) {
+        let _ = IOPMAssertionRelease(id);
+    }
     inner.active = false;
 }
+
+/// Recreate the assertions from scratch, releasing the old ones only once
+/// the new ones exist. IOPM assertions can't be refreshed in place, so this
+/// is how the heartbeat recovers from one silently becoming invalid.
+pub fn heartbeat(inner: &mut Inner) -> Result<(), Error> {
+    if !inner.active {
+        return Ok(());
+    }
+    let new_ids = create_assertions(&inner.reason, inner.kind)?;
+    for id in inner.ids.drain(..) {
+        let _ = IOPMAssertionRelease(id);
+    }
+    inner.ids = new_ids;
+    Ok(())
+}
+
+/// Async wrapper around the (already cheap, synchronous) IOKit power-assertion
+/// calls above, for symmetry with the Linux backend's genuinely async path.
+pub mod nonblocking {
+    use super::*;
+
+    pub type AsyncInner = Inner;
+
+    pub async fn acquire(
+        reason: &str,
+        kind: WakeLockKind,
+        linux: LinuxOptions,
+    ) -> Result<AsyncInner, Error> {
+        super::acquire(reason, kind, linux)
+    }
+
+    pub async fn release(inner: &mut AsyncInner) {
+        super::release(inner);
+    }
+
+    pub fn is_supported() -> bool {
+        super::is_supported()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assertion_types_match_kind() {
+        assert_eq!(
+            assertion_types(WakeLockKind::DisplayAndSystem),
+            &[
+                ASSERTION_TYPE_NO_DISPLAY_SLEEP,
+                ASSERTION_TYPE_PREVENT_IDLE_SYSTEM_SLEEP
+            ]
+        );
+        assert_eq!(
+            assertion_types(WakeLockKind::SystemOnly),
+            &[ASSERTION_TYPE_PREVENT_IDLE_SYSTEM_SLEEP]
+        );
+        assert_eq!(
+            assertion_types(WakeLockKind::DisplayOnly),
+            &[ASSERTION_TYPE_NO_DISPLAY_SLEEP]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::WakeLockResult;
+use crate::{Error, LinuxOptions, WakeLockKind};
 use windows::Win32::Foundation::{CloseHandle, HANDLE};
 use windows::Win32::System::Power::{
     POWER_REQUEST_TYPE, PowerClearRequest, PowerCreateRequest, PowerSetRequest,
@@ -16,14 +16,29 @@ const POWER_REQUEST_CONTEXT_SIMPLE_STRING: u32 = 0x0000_0001;
 const POWER_REQUEST_DISPLAY_REQUIRED: POWER_REQUEST_TYPE = POWER_REQUEST_TYPE(0);
 const POWER_REQUEST_SYSTEM_REQUIRED: POWER_REQUEST_TYPE = POWER_REQUEST_TYPE(1);
 
+const DISPLAY_AND_SYSTEM: [POWER_REQUEST_TYPE; 2] =
+    [POWER_REQUEST_SYSTEM_REQUIRED, POWER_REQUEST_DISPLAY_REQUIRED];
+const SYSTEM_ONLY: [POWER_REQUEST_TYPE; 1] = [POWER_REQUEST_SYSTEM_REQUIRED];
+const DISPLAY_ONLY: [POWER_REQUEST_TYPE; 1] = [POWER_REQUEST_DISPLAY_REQUIRED];
+
+/// `POWER_REQUEST_TYPE` values to set for a given [`WakeLockKind`].
+fn request_types(kind: WakeLockKind) -> &'static [POWER_REQUEST_TYPE] {
+    match kind {
+        WakeLockKind::DisplayAndSystem => &DISPLAY_AND_SYSTEM,
+        WakeLockKind::SystemOnly => &SYSTEM_ONLY,
+        WakeLockKind::DisplayOnly => &DISPLAY_ONLY,
+    }
+}
+
 pub struct PlatformWakeLock {
     handle: HANDLE,
+    kind: WakeLockKind,
     // Keep the buffer alive during the `PowerCreateRequest` call.
     _reason_wide: Vec<u16>,
 }
 
 impl PlatformWakeLock {
-    pub fn acquire(reason: &str) -> WakeLockResult<Self> {
+    pub fn acquire(reason: &str, kind: WakeLockKind) -> Result<Self, Error> {
         let mut reason_wide: Vec<u16> = reason.encode_utf16().collect();
         reason_wide.push(0);
 
@@ -35,12 +50,14 @@ impl PlatformWakeLock {
             },
         };
 
-        let handle = unsafe { PowerCreateRequest(&ctx) }?;
+        let handle =
+            unsafe { PowerCreateRequest(&ctx) }.map_err(|e| Error::Os(e.to_string()))?;
 
-        // Docs recommend pairing DisplayRequired with SystemRequired.
-        if let Err(e) = (|| -> WakeLockResult<()> {
-            unsafe { PowerSetRequest(handle, POWER_REQUEST_SYSTEM_REQUIRED)? };
-            unsafe { PowerSetRequest(handle, POWER_REQUEST_DISPLAY_REQUIRED)? };
+        if let Err(e) = (|| -> Result<(), Error> {
+            for request_type in request_types(kind) {
+                unsafe { PowerSetRequest(handle, *request_type) }
+                    .map_err(|e| Error::Os(e.to_string()))?;
+            }
             Ok(())
         })() {
             unsafe {
@@ -51,16 +68,133 @@ impl PlatformWakeLock {
 
         Ok(Self {
             handle,
+            kind,
             _reason_wide: reason_wide,
         })
     }
 
-    pub fn release(self) -> WakeLockResult<()> {
+    pub fn release(self) -> Result<(), Error> {
         unsafe {
-            let _ = PowerClearRequest(self.handle, POWER_REQUEST_DISPLAY_REQUIRED);
-            let _ = PowerClearRequest(self.handle, POWER_REQUEST_SYSTEM_REQUIRED);
-            CloseHandle(self.handle)?;
+            for request_type in request_types(self.kind) {
+                let _ = PowerClearRequest(self.handle, *request_type);
+            }
+            CloseHandle(self.handle).map_err(|e| Error::Os(e.to_string()))?;
         }
         Ok(())
     }
+
+    /// Re-set the request types on the existing handle. Cheap and idempotent;
+    /// used by the heartbeat as its first refresh. Fails if the handle itself
+    /// is no longer valid.
+    pub fn reassert(&self) -> Result<(), Error> {
+        for request_type in request_types(self.kind) {
+            unsafe { PowerSetRequest(self.handle, *request_type) }
+                .map_err(|e| Error::Os(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Inner {
+    lock: Option<PlatformWakeLock>,
+    active: bool,
+    reason: String,
+    kind: WakeLockKind,
+}
+
+pub fn is_supported() -> bool {
+    true
+}
+
+pub fn acquire(reason: &str, kind: WakeLockKind, _linux: LinuxOptions) -> Result<Inner, Error> {
+    let lock = PlatformWakeLock::acquire(reason, kind)?;
+    Ok(Inner {
+        lock: Some(lock),
+        active: true,
+        reason: reason.to_string(),
+        kind,
+    })
+}
+
+pub fn release(inner: &mut Inner) {
+    if !inner.active {
+        return;
+    }
+    if let Some(lock) = inner.lock.take() {
+        let _ = lock.release();
+    }
+    inner.active = false;
+}
+
+/// Re-assert the existing request; if that fails (e.g. the `HANDLE` itself
+/// became invalid), recreate the whole request from scratch.
+pub fn heartbeat(inner: &mut Inner) -> Result<(), Error> {
+    if !inner.active {
+        return Ok(());
+    }
+    if let Some(lock) = &inner.lock {
+        if lock.reassert().is_ok() {
+            return Ok(());
+        }
+    }
+
+    let old = inner.lock.take();
+    match PlatformWakeLock::acquire(&inner.reason, inner.kind) {
+        Ok(new_lock) => {
+            if let Some(old) = old {
+                let _ = old.release();
+            }
+            inner.lock = Some(new_lock);
+            Ok(())
+        }
+        Err(e) => {
+            inner.lock = old;
+            Err(e)
+        }
+    }
+}
+
+/// Async wrapper around the (already cheap, synchronous) Win32 power-request
+/// calls above, for symmetry with the Linux backend's genuinely async path.
+pub mod nonblocking {
+    use super::*;
+
+    pub type AsyncInner = Inner;
+
+    pub async fn acquire(
+        reason: &str,
+        kind: WakeLockKind,
+        linux: LinuxOptions,
+    ) -> Result<AsyncInner, Error> {
+        super::acquire(reason, kind, linux)
+    }
+
+    pub async fn release(inner: &mut AsyncInner) {
+        super::release(inner);
+    }
+
+    pub fn is_supported() -> bool {
+        super::is_supported()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_types_match_kind() {
+        assert_eq!(
+            request_types(WakeLockKind::DisplayAndSystem),
+            &[POWER_REQUEST_SYSTEM_REQUIRED, POWER_REQUEST_DISPLAY_REQUIRED]
+        );
+        assert_eq!(
+            request_types(WakeLockKind::SystemOnly),
+            &[POWER_REQUEST_SYSTEM_REQUIRED]
+        );
+        assert_eq!(
+            request_types(WakeLockKind::DisplayOnly),
+            &[POWER_REQUEST_DISPLAY_REQUIRED]
+        );
+    }
 }